@@ -16,25 +16,425 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use clap::{Parser, Subcommand};
-use libpulse_binding::volume::Volume;
+use clap::{Parser, Subcommand, ValueEnum};
+use libpulse_binding::volume::{ChannelVolumes, Volume};
 use pulsectl::controllers::DeviceControl;
 use pulsectl::controllers::SinkController;
+use pulsectl::controllers::SourceController;
 use pulsectl::controllers::types::DeviceInfo;
 use std::{env, fs, path::Path, thread, time};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+
+/// Bind multimedia volume keys straight off evdev, the way PulseAudio's own
+/// `module-mmkbd-evdev` does, so fading works without a separate hotkey daemon.
+mod daemon {
+    use super::{AtomicBool, Arc, Ordering};
+    use std::collections::HashMap;
+    use std::{path::PathBuf, sync::mpsc, thread, time};
+
+    const REPEAT_INTERVAL: time::Duration = time::Duration::from_millis(200);
+
+    #[derive(Clone, Copy, Debug)]
+    pub enum VolumeKey {
+        Up,
+        Down,
+        Mute,
+    }
+
+    fn matching_device_paths(device_glob: &str) -> Vec<PathBuf> {
+        match glob::glob(device_glob) {
+            Ok(paths) => paths.filter_map(Result::ok).collect(),
+            Err(err) => {
+                eprintln!("Warning: invalid --device-glob '{}': {}", device_glob, err);
+                Vec::new()
+            }
+        }
+    }
+
+    fn has_volume_keys(device: &evdev::Device) -> bool {
+        device.supported_keys().is_some_and(|keys| {
+            keys.contains(evdev::Key::KEY_VOLUMEUP)
+                || keys.contains(evdev::Key::KEY_VOLUMEDOWN)
+                || keys.contains(evdev::Key::KEY_MUTE)
+        })
+    }
+
+    /// watch a single device's key events, firing `tx` on press and re-firing
+    /// every `REPEAT_INTERVAL` for as long as the key stays held down
+    fn watch_device(mut device: evdev::Device, tx: mpsc::Sender<VolumeKey>) {
+        let mut held: HashMap<evdev::Key, Arc<AtomicBool>> = HashMap::new();
+
+        loop {
+            let events = match device.fetch_events() {
+                Ok(events) => events,
+                Err(_) => return,
+            };
+            for event in events {
+                if event.event_type() != evdev::EventType::KEY {
+                    continue;
+                }
+                let evdev_key = evdev::Key::new(event.code());
+                let key = match evdev_key {
+                    evdev::Key::KEY_VOLUMEUP => VolumeKey::Up,
+                    evdev::Key::KEY_VOLUMEDOWN => VolumeKey::Down,
+                    evdev::Key::KEY_MUTE => VolumeKey::Mute,
+                    _ => continue,
+                };
+
+                match event.value() {
+                    // key down: fire once immediately, then keep repeating
+                    // ourselves rather than relying on kernel autorepeat,
+                    // which most volume-key devices don't enable at all
+                    1 => {
+                        if tx.send(key).is_err() {
+                            return;
+                        }
+                        let flag = Arc::new(AtomicBool::new(true));
+                        spawn_repeater(flag.clone(), key, tx.clone());
+                        held.insert(evdev_key, flag);
+                    }
+                    // key up: stop that key's repeater
+                    0 => {
+                        if let Some(flag) = held.remove(&evdev_key) {
+                            flag.store(false, Ordering::Relaxed);
+                        }
+                    }
+                    // kernel autorepeat (2): ignored, we drive our own timing
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn spawn_repeater(held: Arc<AtomicBool>, key: VolumeKey, tx: mpsc::Sender<VolumeKey>) {
+        thread::spawn(move || {
+            while held.load(Ordering::Relaxed) {
+                thread::sleep(REPEAT_INTERVAL);
+                // the key may have been released while we slept
+                if !held.load(Ordering::Relaxed) || tx.send(key).is_err() {
+                    return;
+                }
+            }
+        });
+    }
+
+    /// spawn one watcher thread per matching device, returning how many were started
+    pub fn watch(device_glob: &str, tx: mpsc::Sender<VolumeKey>) -> usize {
+        let mut watched = 0;
+        for path in matching_device_paths(device_glob) {
+            let mut device = match evdev::Device::open(&path) {
+                Ok(d) => d,
+                Err(_) => continue,
+            };
+            if !has_volume_keys(&device) {
+                continue;
+            }
+            // grab the device so volume keys stop reaching the focused
+            // application too, the way module-mmkbd-evdev does; a device
+            // another process already grabbed (EBUSY) is still watched,
+            // just without that exclusivity, rather than skipped outright
+            if let Err(err) = device.grab() {
+                eprintln!(
+                    "Warning: could not grab {} ({}); its volume keys will also reach the focused window.",
+                    path.display(),
+                    err
+                );
+            }
+            let tx = tx.clone();
+            thread::spawn(move || watch_device(device, tx));
+            watched += 1;
+        }
+        watched
+    }
+}
+
+/// OSD feedback via the desktop's notification daemon, for WM setups with no
+/// other way to see a fade in progress.
+mod notify {
+    use notify_rust::{Hint, Notification, NotificationHandle};
+
+    // fixed id so repeated fade notifications replace the previous popup in
+    // place instead of stacking new ones, same as SwayOSD/pnmixer do
+    const REPLACE_ID: u32 = 0x564f_4c46; // "VOLF"
+
+    /// show (or, once a popup is already up, update in place) the fade OSD.
+    ///
+    /// `handle` is owned by the caller across an entire fade's step loop: the
+    /// first call opens the notification and stashes the handle, every call
+    /// after that mutates and re-sends the *same* handle instead of opening a
+    /// fresh D-Bus round trip per step, which would otherwise add its own
+    /// latency/jitter to the fade it's narrating.
+    pub fn show_fade(handle: &mut Option<NotificationHandle>, device_label: &str, percent: f64, muted: bool) {
+        let percent = percent.round().clamp(0.0, 100.0);
+        let body = if muted {
+            "Muted".to_string()
+        } else {
+            format!("{:.0}%", percent)
+        };
+        // GNOME's volume OSD reads the progress bar off this custom int hint
+        let hint = Hint::CustomInt("value".to_owned(), percent as i32);
+
+        if let Some(handle) = handle {
+            handle.summary(device_label);
+            handle.body(&body);
+            handle.hint(hint);
+            handle.update();
+            return;
+        }
+
+        match Notification::new()
+            .summary(device_label)
+            .body(&body)
+            .hint(hint)
+            .id(REPLACE_ID)
+            .timeout(1500)
+            .show()
+        {
+            Ok(new_handle) => *handle = Some(new_handle),
+            // OSD feedback is best-effort: a missing/unreachable notification
+            // daemon should never fail a fade
+            Err(err) => eprintln!("Warning: could not show notification: {}", err),
+        }
+    }
+}
 
 const DEFAULT_VOLUME: Volume = Volume(65536 / 4); // 25% volume
 const DEFAULT_INCREMENT: f64 = 5.0;
 const DEFAULT_DECREMENT: f64 = 5.0;
 
-const INC_STEPS: u8 = 10;
-const DEC_STEPS: u8 = 10;
+// PulseAudio's PA_VOLUME_NORM: the raw Volume value representing 100%/0dB gain.
+const PA_VOLUME_NORM: u32 = 65536;
+
+// floor applied to linear gain before taking a log/ratio, so fading from or to
+// mute never has to divide by (or take the root of) zero
+const GAIN_FLOOR: f64 = 1e-4;
 
-const FADE_IN_INCREMENT_PER_STEP: f64 = 9.0;
-const FADE_OUT_DECREMENT_PER_STEP: f64 = 20.0;
+const DEFAULT_DURATION_MS: u64 = 260;
 
 const WAIT_BETWEEN_STEPS: time::Duration = time::Duration::from_millis(26);
 
+/// Which kind of PulseAudio device a fade should act on.
+///
+/// Mirrors SwayOSD's unification of `change_sink_volume`/`change_source_volume`
+/// into a single device-typed entry point: every fade primitive below takes
+/// this enum instead of being duplicated per controller.
+#[derive(Clone, Copy, Debug)]
+enum DeviceKind {
+    /// playback device (speakers, headphones, HDMI, ...)
+    Sink,
+    /// capture device (microphone, line-in, ...)
+    Source,
+}
+
+impl DeviceKind {
+    /// cache key suffix so sink and source "previous volume" files don't collide
+    fn cache_suffix(&self) -> &'static str {
+        match self {
+            DeviceKind::Sink => "sink",
+            DeviceKind::Source => "source",
+        }
+    }
+}
+
+enum VolumeDeviceType {
+    Sink(SinkController),
+    Source(SourceController),
+}
+
+impl VolumeDeviceType {
+    fn create(kind: DeviceKind) -> Result<Self, pulsectl::ControllerError> {
+        match kind {
+            DeviceKind::Sink => SinkController::create().map(VolumeDeviceType::Sink),
+            DeviceKind::Source => SourceController::create().map(VolumeDeviceType::Source),
+        }
+    }
+
+    fn kind(&self) -> DeviceKind {
+        match self {
+            VolumeDeviceType::Sink(_) => DeviceKind::Sink,
+            VolumeDeviceType::Source(_) => DeviceKind::Source,
+        }
+    }
+
+    fn get_default_device(&mut self) -> Result<DeviceInfo, pulsectl::ControllerError> {
+        match self {
+            VolumeDeviceType::Sink(h) => h.get_default_device(),
+            VolumeDeviceType::Source(h) => h.get_default_device(),
+        }
+    }
+
+    fn set_device_mute_by_index(&mut self, dev_idx: u32, mute: bool) {
+        match self {
+            VolumeDeviceType::Sink(h) => h.set_device_mute_by_index(dev_idx, mute),
+            VolumeDeviceType::Source(h) => h.set_device_mute_by_index(dev_idx, mute),
+        }
+    }
+
+    fn list_devices(&mut self) -> Result<Vec<DeviceInfo>, pulsectl::ControllerError> {
+        match self {
+            VolumeDeviceType::Sink(h) => h.list_devices(),
+            VolumeDeviceType::Source(h) => h.list_devices(),
+        }
+    }
+
+    fn set_device_volume_by_index(&mut self, dev_idx: u32, volume: &ChannelVolumes) {
+        match self {
+            VolumeDeviceType::Sink(h) => h.set_device_volume_by_index(dev_idx, volume),
+            VolumeDeviceType::Source(h) => h.set_device_volume_by_index(dev_idx, volume),
+        }
+    }
+
+    /// channel map of a specific device, looked up by index rather than assumed default
+    fn channels_of(&mut self, dev_idx: u32) -> ChannelVolumes {
+        self.list_devices()
+            .expect("Could not list devices.")
+            .into_iter()
+            .find(|d| d.index == dev_idx)
+            .expect("Device disappeared mid-fade.")
+            .volume
+    }
+}
+
+/// Shape of a fade over its duration.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Curve {
+    /// interpolate directly in the volume domain (what volfade has always done)
+    Linear,
+    /// interpolate linearly in the linear-gain domain, then map back to a
+    /// `Volume` with the cubic curve PulseAudio itself uses
+    Cubic,
+    /// interpolate geometrically in the linear-gain domain, which matches
+    /// perceived loudness more closely near the top and bottom of the range
+    Exponential,
+}
+
+/// convert a raw `Volume` to PulseAudio's true linear gain in `(0, 1]`: PA
+/// maps `Volume` to gain cubically (`gain = (v/NORM)^3`), floored to avoid
+/// raising zero to a fractional power below
+fn to_gain(v: Volume) -> f64 {
+    (v.0 as f64 / PA_VOLUME_NORM as f64).powi(3).max(GAIN_FLOOR)
+}
+
+/// invert `to_gain`: map linear gain back to a raw `Volume` via PA's
+/// cube-root curve, so the pair round-trips to within rounding for volumes
+/// whose gain lies above `GAIN_FLOOR`
+fn from_gain(gain: f64) -> Volume {
+    Volume((PA_VOLUME_NORM as f64 * gain.cbrt()).round() as u32)
+}
+
+impl Curve {
+    /// volume at step `i` of `steps` while fading from `start` to `target`
+    fn at(&self, start: Volume, target: Volume, i: u32, steps: u32) -> Volume {
+        let t = i as f64 / steps as f64;
+        match self {
+            Curve::Linear => {
+                let v = start.0 as f64 + (target.0 as f64 - start.0 as f64) * t;
+                Volume(v.round() as u32)
+            }
+            Curve::Cubic => {
+                let g0 = to_gain(start);
+                let g1 = to_gain(target);
+                from_gain(g0 + (g1 - g0) * t)
+            }
+            Curve::Exponential => {
+                let g0 = to_gain(start);
+                let g1 = to_gain(target);
+                from_gain(g0 * (g1 / g0).powf(t))
+            }
+        }
+    }
+}
+
+/// Settings shared by every fade, threaded through instead of growing each
+/// function's argument list every time a new knob is added.
+#[derive(Clone, Debug)]
+struct FadeOptions {
+    duration: time::Duration,
+    curve: Curve,
+    /// device label to show on an OSD notification while fading, if requested
+    notify: Option<String>,
+}
+
+/// fade `dev_idx` from its current volume to `target`, per `opts`
+///
+/// This always steps the volume client-side. An earlier revision tried a
+/// `--server-ramp` mode to hand PulseAudio's server-side mixing ramp a single
+/// target+duration instead, but that ramp is internal server infrastructure
+/// with no verb in libpulse's client protocol, and `pulsectl::DeviceControl`
+/// exposes nothing close to it either — there's no real API to build it on,
+/// so the request is won't-do rather than a gap to fill later.
+fn fade(handler: &mut VolumeDeviceType, dev_idx: u32, target: Volume, opts: &FadeOptions) {
+    let start = get_current_vol(handler, dev_idx);
+    let mut channels = handler.channels_of(dev_idx);
+    let muted = target == Volume::MUTED;
+
+    // held across the whole loop so every step updates the same popup in
+    // place rather than opening a new one
+    let mut notify_handle = None;
+
+    let steps = (opts.duration.as_millis() / WAIT_BETWEEN_STEPS.as_millis()).max(1) as u32;
+    for i in 1..=steps {
+        let step_vol = opts.curve.at(start, target, i, steps);
+        channels.set(channels.len() as u32, step_vol);
+        handler.set_device_volume_by_index(dev_idx, &channels);
+        if let Some(label) = &opts.notify {
+            let percent = step_vol.0 as f64 / PA_VOLUME_NORM as f64 * 100.0;
+            notify::show_fade(&mut notify_handle, label, percent, muted);
+        }
+        thread::sleep(WAIT_BETWEEN_STEPS);
+    }
+}
+
+/// print one `list` row: index, name, description and current volume percent
+fn print_device(device: &DeviceInfo) {
+    let percent = device.volume.avg().0 as f64 / PA_VOLUME_NORM as f64 * 100.0;
+    println!(
+        "{}: {} ({}) - {:.0}%",
+        device.index,
+        device.name.as_deref().unwrap_or("?"),
+        device.description.as_deref().unwrap_or("?"),
+        percent,
+    );
+}
+
+/// Output shape for the `status` subcommand.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum StatusFormat {
+    /// i3bar/swaybar JSON protocol block: `{"full_text":...,"percentage":...}`
+    I3bar,
+    /// plain JSON object: `{"percent":...,"muted":...}`
+    Json,
+    /// human-readable one-liner
+    Plain,
+}
+
+/// print the selected device's current volume/mute state for a status bar
+fn print_status(device: &DeviceInfo, format: StatusFormat) {
+    let percent = (device.volume.avg().0 as f64 / PA_VOLUME_NORM as f64 * 100.0).round() as i64;
+    let muted = device.mute;
+
+    match format {
+        StatusFormat::I3bar => {
+            let icon = if muted { "\u{1f507}" } else { "\u{1f50a}" };
+            let color = if muted { "#a6a6a6" } else { "#ffffff" };
+            println!(
+                "{{\"full_text\":\"{icon} {percent}%\",\"percentage\":{percent},\"color\":\"{color}\"}}"
+            );
+        }
+        StatusFormat::Json => {
+            println!("{{\"percent\":{percent},\"muted\":{muted}}}");
+        }
+        StatusFormat::Plain => {
+            if muted {
+                println!("{percent}% (muted)");
+            } else {
+                println!("{percent}%");
+            }
+        }
+    }
+}
+
 // fn get_current_vol(handler: &mut SinkController) -> Result<Volume, pulsectl::Error> {
 //     let default_device: DeviceInfo = match handler.get_default_device() {
 //         Ok(d) => d,
@@ -47,15 +447,10 @@ const WAIT_BETWEEN_STEPS: time::Duration = time::Duration::from_millis(26);
 //     Ok(device.volume.avg())
 // }
 
-fn get_current_vol(handler: &mut SinkController) -> Volume {
-    let default_device: DeviceInfo = handler
-        .get_default_device()
-        .expect("Could not get default playback device.");
-
-
-    let device = default_device;
-
-    device.volume.avg()
+// looks up the addressed device (not necessarily the default one: --device
+// may have picked another sink/source) and reads its current volume
+fn get_current_vol(handler: &mut VolumeDeviceType, dev_idx: u32) -> Volume {
+    handler.channels_of(dev_idx).avg()
 }
 
 enum ToFile {
@@ -66,7 +461,7 @@ enum ToFile {
 enum VolumeCache {}
 
 impl VolumeCache {
-    fn get_path() -> String {
+    fn get_path(kind: DeviceKind) -> String {
         let cache_dir = match env::var("XDG_CACHE_HOME") {
             Ok(dir) => {
                 format!("{}/volfade-rs", dir)
@@ -82,17 +477,18 @@ impl VolumeCache {
                 .expect("Failed to create cache directory");
         };
 
-        let filename = "previous_volume";
-        let cache_path = cache_dir + "/" + filename;
+        let filename = format!("previous_volume_{}", kind.cache_suffix());
+        let cache_path = cache_dir + "/" + &filename;
         cache_path
     }
-    fn save(handler: &mut SinkController, t: ToFile) {
+    fn save(handler: &mut VolumeDeviceType, dev_idx: u32, t: ToFile) {
         let vol = match t {
             ToFile::FromBuffer(buffered_vol) => buffered_vol,
-            ToFile::_FromCurrentVolume => CurrentVolume::get(handler),
+            ToFile::_FromCurrentVolume => CurrentVolume::get(handler, dev_idx),
         };
+        let kind = handler.kind();
         let vol = vol.0;
-        fs::write(VolumeCache::get_path(), vol.to_le_bytes())
+        fs::write(VolumeCache::get_path(kind), vol.to_le_bytes())
             .expect("Unable to write pre_vol file");
     }
 }
@@ -100,22 +496,16 @@ impl VolumeCache {
 enum CurrentVolume {}
 
 impl CurrentVolume {
-    fn get(handler: &mut SinkController) -> Volume {
-        let default_device: DeviceInfo = handler
-            .get_default_device()
-            .expect("Could not get default playback device.");
-
-        let device = default_device;
-
-        device.volume.avg()
+    fn get(handler: &mut VolumeDeviceType, dev_idx: u32) -> Volume {
+        handler.channels_of(dev_idx).avg()
     }
 }
 
 type PreviousVolume = VolumeCache;
 
 impl PreviousVolume {
-    fn query() -> Option<Volume> {
-        match fs::read(VolumeCache::get_path()) {
+    fn query(kind: DeviceKind) -> Option<Volume> {
+        match fs::read(VolumeCache::get_path(kind)) {
             Ok(data) => {
                 let vol = u32::from_le_bytes(
                     data
@@ -129,75 +519,88 @@ impl PreviousVolume {
     }
 }
 
-fn inc_vol(handler: &mut SinkController, dev_idx: u32, increment: f64, target_volume: Option<Volume>) {
-    let inc_percent_per_step: f64 = (increment / 100.0) / INC_STEPS as f64;
-
+fn inc_vol(handler: &mut VolumeDeviceType, dev_idx: u32, increment: f64, opts: &FadeOptions) {
     // crescendo
     handler.set_device_mute_by_index(dev_idx, false);
-    let mut i = 0;
-    while i < INC_STEPS {
-        // stop crescendo if target volume is reached between increment steps
-        if let Some(target_volume) = target_volume {
-            if get_current_vol(handler).ge(&target_volume) {
-                break;
-            };
-        };
-        handler.increase_device_volume_by_percent(dev_idx, inc_percent_per_step);
-        thread::sleep(WAIT_BETWEEN_STEPS);
-        i += 1;
-    };
+    let start = get_current_vol(handler, dev_idx);
+    let delta = (increment / 100.0 * PA_VOLUME_NORM as f64).round() as u32;
+    let target = Volume(start.0.saturating_add(delta));
+    fade(handler, dev_idx, target, opts);
 }
 
-fn dec_vol(handler: &mut SinkController, dev_idx: u32, decrement: f64) {
-    let dec_percent_per_step: f64 = (decrement / 100.0) / DEC_STEPS as f64;
-
+fn dec_vol(handler: &mut VolumeDeviceType, dev_idx: u32, decrement: f64, opts: &FadeOptions) {
     // diminuendo
-    let mut i = 0;
-    while i < DEC_STEPS {
-        handler.decrease_device_volume_by_percent(dev_idx, dec_percent_per_step);
-        thread::sleep(WAIT_BETWEEN_STEPS);
-        i += 1;
-    };
+    let start = get_current_vol(handler, dev_idx);
+    let delta = (decrement / 100.0 * PA_VOLUME_NORM as f64).round() as u32;
+    let target = Volume(start.0.saturating_sub(delta));
+    fade(handler, dev_idx, target, opts);
 }
 
-fn mute(handler: &mut SinkController, dev_idx: u32, decrement_per_step: f64) {
-    if get_current_vol(handler).eq(&Volume::MUTED) {
+fn mute(handler: &mut VolumeDeviceType, dev_idx: u32, opts: &FadeOptions) {
+    if get_current_vol(handler, dev_idx).eq(&Volume::MUTED) {
         return;
     };
 
     // store current volume before fading out
-    // VolCache::save(handler, ToFile::_FromCurrentVolume);
-    let vol_buffer: Volume = CurrentVolume::get(handler);
+    // VolCache::save(handler, dev_idx, ToFile::_FromCurrentVolume);
+    let vol_buffer: Volume = CurrentVolume::get(handler, dev_idx);
 
     // fade out
-    while get_current_vol(handler).gt(&Volume::MUTED) {
-        dec_vol(handler, dev_idx, decrement_per_step);
-    };
+    fade(handler, dev_idx, Volume::MUTED, opts);
     handler.set_device_mute_by_index(dev_idx, true);
 
     // in case we want to fade in later
-    VolumeCache::save(handler, ToFile::FromBuffer(vol_buffer));
+    VolumeCache::save(handler, dev_idx, ToFile::FromBuffer(vol_buffer));
 }
 
-fn unmute(handler: &mut SinkController, dev_idx: u32, increment_per_step: f64) {
+fn unmute(handler: &mut VolumeDeviceType, dev_idx: u32, opts: &FadeOptions) {
     // set target volume from previously saved volume
-    let target_volume: Volume = PreviousVolume::query().unwrap();
+    let target_volume: Volume = PreviousVolume::query(handler.kind()).unwrap();
 
     // fade in
     handler.set_device_mute_by_index(dev_idx, false);
-    while get_current_vol(handler).lt(&target_volume) {
-        inc_vol(handler, dev_idx, increment_per_step, Some(target_volume));
-    };
+    fade(handler, dev_idx, target_volume, opts);
 }
 
-fn toggle_mute(handler: &mut SinkController, dev_idx: u32) {
-    if get_current_vol(handler).gt(&Volume::MUTED) {
-        mute(handler, dev_idx, FADE_OUT_DECREMENT_PER_STEP);
+fn toggle_mute(handler: &mut VolumeDeviceType, dev_idx: u32, opts: &FadeOptions) {
+    if get_current_vol(handler, dev_idx).gt(&Volume::MUTED) {
+        mute(handler, dev_idx, opts);
     } else {
-        unmute(handler, dev_idx, FADE_IN_INCREMENT_PER_STEP);
+        unmute(handler, dev_idx, opts);
     };
 }
 
+const DEFAULT_DEVICE_GLOB: &str = "/dev/input/event*";
+
+/// watch `device_glob` for multimedia volume keys and fade in response until killed
+fn run_daemon(handler: &mut VolumeDeviceType, dev_idx: u32, opts: &FadeOptions, device_glob: &str) {
+    let (tx, rx) = mpsc::channel();
+    let watched = daemon::watch(device_glob, tx);
+    if watched == 0 {
+        eprintln!(
+            "Warning: no input device matching '{}' exposes volume keys.",
+            device_glob
+        );
+        return;
+    }
+    println!("Watching {} input device(s) for volume keys.", watched);
+
+    while let Ok(mut key) = rx.recv() {
+        // a fade (opts.duration, 260ms by default) outlasts the repeater's
+        // 200ms interval, so holding a key can queue events faster than
+        // they drain; collapse the backlog down to the most recent one so
+        // fading doesn't keep running well after the key is released
+        for queued in rx.try_iter() {
+            key = queued;
+        }
+        match key {
+            daemon::VolumeKey::Up => inc_vol(handler, dev_idx, DEFAULT_INCREMENT, opts),
+            daemon::VolumeKey::Down => dec_vol(handler, dev_idx, DEFAULT_DECREMENT, opts),
+            daemon::VolumeKey::Mute => toggle_mute(handler, dev_idx, opts),
+        }
+    }
+}
+
 /// Volfaders change the volume levels with smooth fading transitions (for PulseAudio).
 #[derive(Parser)]
 #[command(author = "Juan de Dios Hernández, <86342863+macydnah@users.noreply.github.com>")]
@@ -205,10 +608,83 @@ fn toggle_mute(handler: &mut SinkController, dev_idx: u32) {
 #[command(propagate_version = true)]
 #[group(id = "dynamics", required = false, multiple = false)]
 struct Cli {
+    /// operate on the default source (microphone) instead of the default sink
+    #[arg(long, global = true)]
+    source: bool,
+
+    /// address a specific device by index or name instead of the default
+    #[arg(long, global = true, value_name = "INDEX|NAME")]
+    device: Option<String>,
+
+    /// how long the fade should take, in milliseconds
+    #[arg(long, global = true, value_name = "MS", default_value_t = DEFAULT_DURATION_MS)]
+    duration: u64,
+
+    /// easing curve applied over the fade's duration
+    #[arg(long, global = true, value_enum, default_value_t = Curve::Linear)]
+    curve: Curve,
+
+    /// show an OSD notification with a volume bar while fading
+    #[arg(long, global = true)]
+    notify: bool,
+
     #[command(subcommand)]
     dynamics: Dynamics,
 }
 
+impl Cli {
+    fn device_kind(&self) -> DeviceKind {
+        if self.source {
+            DeviceKind::Source
+        } else {
+            DeviceKind::Sink
+        }
+    }
+
+    fn fade_options(&self, device: &DeviceInfo) -> FadeOptions {
+        let notify = self.notify.then(|| {
+            device
+                .description
+                .clone()
+                .or_else(|| device.name.clone())
+                .unwrap_or_else(|| "Volume".to_string())
+        });
+
+        FadeOptions {
+            duration: time::Duration::from_millis(self.duration),
+            curve: self.curve,
+            notify,
+        }
+    }
+}
+
+/// Resolve `--device <index|name>` against the devices the handler currently
+/// knows about, falling back to the default device when unset.
+fn find_device(handler: &mut VolumeDeviceType, selector: Option<&str>) -> DeviceInfo {
+    let selector = match selector {
+        Some(s) => s,
+        None => {
+            return handler
+                .get_default_device()
+                .expect("Could not get default device.");
+        }
+    };
+
+    let devices = handler
+        .list_devices()
+        .expect("Could not list devices.");
+
+    let found = if let Ok(index) = selector.parse::<u32>() {
+        devices.into_iter().find(|d| d.index == index)
+    } else {
+        devices
+            .into_iter()
+            .find(|d| d.name.as_deref() == Some(selector))
+    };
+
+    found.unwrap_or_else(|| panic!("No device found matching '{}'", selector))
+}
+
 /// Dynamics
 #[derive(Subcommand)]
 #[command(long_about = None, rename_all = "kebab-case")]
@@ -233,69 +709,156 @@ enum Dynamics {
 
     /// al niente (fade out to mute)
     #[command(alias = "m")]
-    Mute {
-        /// how much volume percent to decrease per step
-        #[arg(default_value_t = FADE_OUT_DECREMENT_PER_STEP)]
-        decrement_per_step: f64,
-    },
+    Mute,
 
     /// dal niente (fade in from mute)
     #[command(alias = "u")]
-    Unmute {
-        /// how much volume percent to increase per step
-        #[arg(default_value_t = FADE_IN_INCREMENT_PER_STEP)]
-        increment_per_step: f64,
-    },
+    Unmute,
 
     /// toggle al niente/dal niente
     #[command(alias = "t")]
     ToggleMute,
+
+    /// list sinks and sources with their index, name, description and volume
+    List,
+
+    /// bind multimedia volume keys (evdev) and fade in response, for bare WMs
+    Daemon {
+        /// which /dev/input/event* nodes to watch
+        #[arg(long, default_value = DEFAULT_DEVICE_GLOB)]
+        device_glob: String,
+    },
+
+    /// print the current volume/mute state, for i3status/swaybar-style status bars
+    Status {
+        /// output format
+        #[arg(long, value_enum, default_value_t = StatusFormat::I3bar)]
+        format: StatusFormat,
+    },
 }
 
 fn main() -> Result<(), pulsectl::Error> {
     let args = Cli::parse();
 
-    // create handler that calls functions on playback devices and apps
-    let mut handler = match SinkController::create() {
-        Ok(h) => h,
-        Err(msg) => {
-            eprintln!("Error! Could not create PulseAudio handler:\n");
-            return Err(pulsectl::Error::Controller(msg))
+    if let Dynamics::List = args.dynamics {
+        let mut sinks = SinkController::create()?;
+        println!("Sinks:");
+        for device in sinks.list_devices()? {
+            print_device(&device);
         }
-    };
 
-    let default_device: DeviceInfo = match handler.get_default_device() {
-        Ok(d) => d,
+        let mut sources = SourceController::create()?;
+        println!("Sources:");
+        for device in sources.list_devices()? {
+            print_device(&device);
+        }
+        return Ok(());
+    }
+
+    // create handler that calls functions on playback/capture devices and apps
+    let mut handler = match VolumeDeviceType::create(args.device_kind()) {
+        Ok(h) => h,
         Err(msg) => {
-            eprintln!("Error! Could not get default playback device:\n");
+            eprintln!("Error! Could not create PulseAudio handler:\n");
             return Err(pulsectl::Error::Controller(msg))
         }
     };
 
-    let device = default_device;
+    let device = find_device(&mut handler, args.device.as_deref());
+    let opts = args.fade_options(&device);
 
     match args.dynamics {
         // Dynamics::Increase => {
         Dynamics::Increase { increment } => {
             print!("Crescendo\n");
-            inc_vol(&mut handler, device.index, increment, None);
+            inc_vol(&mut handler, device.index, increment, &opts);
         }
         Dynamics::Decrease { decrement } => {
             print!("Diminuendo\n");
-            dec_vol(&mut handler, device.index, decrement);
+            dec_vol(&mut handler, device.index, decrement, &opts);
         }
-        Dynamics::Mute { decrement_per_step } => {
+        Dynamics::Mute => {
             print!("Diminuendo al niente\n");
-            mute(&mut handler, device.index, decrement_per_step);
+            mute(&mut handler, device.index, &opts);
         }
-        Dynamics::Unmute { increment_per_step } => {
+        Dynamics::Unmute => {
             print!("Crescendo dal niente\n");
-            unmute(&mut handler, device.index, increment_per_step);
+            unmute(&mut handler, device.index, &opts);
         }
         Dynamics::ToggleMute => {
             print!("Toggled mute state\n");
-            toggle_mute(&mut handler, device.index);
+            toggle_mute(&mut handler, device.index, &opts);
         }
+        Dynamics::Daemon { device_glob } => {
+            run_daemon(&mut handler, device.index, &opts, &device_glob);
+        }
+        Dynamics::Status { format } => {
+            print_status(&device, format);
+        }
+        Dynamics::List => unreachable!("handled earlier"),
     };
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn curve_reaches_target_at_final_step() {
+        let start = Volume(PA_VOLUME_NORM / 4);
+        let target = Volume(PA_VOLUME_NORM);
+        let steps = 10;
+        for curve in [Curve::Linear, Curve::Cubic, Curve::Exponential] {
+            assert_eq!(curve.at(start, target, steps, steps), target);
+        }
+    }
+
+    #[test]
+    fn curve_starts_at_start_step_zero() {
+        let start = Volume(PA_VOLUME_NORM / 4);
+        let target = Volume(PA_VOLUME_NORM);
+        let steps = 10;
+        for curve in [Curve::Linear, Curve::Cubic, Curve::Exponential] {
+            assert_eq!(curve.at(start, target, 0, steps), start);
+        }
+    }
+
+    #[test]
+    fn gain_round_trips_through_volume() {
+        // stay above the ~4.6% volume where GAIN_FLOOR clamps the gain, so
+        // this is exercising the round-trip rather than the floor
+        for percent in [10u32, 25, 50, 75, 100] {
+            let vol = Volume(PA_VOLUME_NORM * percent / 100);
+            let gain = to_gain(vol);
+            let round_tripped = from_gain(gain);
+            // cbrt/round-trip through the cubic mapping can be off by a
+            // rounding unit or two, never by a meaningful fraction of a percent
+            assert!(
+                (round_tripped.0 as i64 - vol.0 as i64).abs() <= 2,
+                "{:?} round-tripped to {:?}",
+                vol,
+                round_tripped
+            );
+        }
+    }
+
+    #[test]
+    fn exponential_fade_is_monotonic() {
+        let start = Volume(PA_VOLUME_NORM / 100); // near-silent
+        let target = Volume(PA_VOLUME_NORM); // full volume
+        let steps = 20;
+        let mut prev = start.0;
+        for i in 1..=steps {
+            let step_vol = Curve::Exponential.at(start, target, i, steps);
+            assert!(
+                step_vol.0 >= prev,
+                "step {} ({:?}) was quieter than the previous step ({:?})",
+                i,
+                step_vol,
+                prev
+            );
+            prev = step_vol.0;
+        }
+    }
+}